@@ -1,9 +1,19 @@
-use std::fmt;
+use std::{
+  any::{Any, TypeId},
+  collections::HashMap,
+  fmt,
+  pin::Pin,
+  sync::{Mutex, OnceLock},
+  time::{Duration, Instant},
+};
 
 use anyhow::anyhow;
+use chrono::Utc;
+use futures::{future::join_all, FutureExt, Stream, StreamExt, TryStreamExt};
 use k8s_openapi::{
   api::{
     apps::v1::{DaemonSet, Deployment, ReplicaSet, StatefulSet},
+    authorization::v1::{ResourceAttributes, SelfSubjectAccessReview, SelfSubjectAccessReviewSpec},
     batch::v1::{CronJob, Job},
     core::v1::{
       ConfigMap, Namespace, Node, PersistentVolume, PersistentVolumeClaim, Pod,
@@ -13,11 +23,18 @@ use k8s_openapi::{
     rbac::v1::{ClusterRole, ClusterRoleBinding, Role, RoleBinding},
     storage::v1::StorageClass,
   },
+  apimachinery::pkg::apis::meta::v1::DeleteOptions,
   NamespaceResourceScope,
 };
 use kube::{
-  api::{ListMeta, ListParams, ObjectList},
+  api::{
+    ApiResource as DynamicApiResource, DynamicObject, EvictParams, ListMeta, ListParams,
+    ObjectList, Patch, PatchParams, PostParams,
+  },
   config::Kubeconfig,
+  core::GroupVersionKind,
+  discovery::{Discovery, Scope},
+  runtime::{reflector, watcher, WatchStreamExt},
   Api, Resource as ApiResource,
 };
 use kubectl_view_allocations::{
@@ -25,6 +42,8 @@ use kubectl_view_allocations::{
   extract_utilizations_from_pod_metrics, make_qualifiers, metrics::PodMetrics, Resource,
 };
 use serde::de::DeserializeOwned;
+use serde_json::json;
+use tokio::time::sleep;
 
 use super::Network;
 use crate::app::{
@@ -51,6 +70,202 @@ use crate::app::{
   svcs::KubeSvc,
 };
 
+/// A cache key for one (resource, verb) access check.
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct PermissionKey {
+  pub resource: String,
+  pub verb: String,
+}
+
+impl PermissionKey {
+  fn new(resource: &str, verb: &str) -> Self {
+    PermissionKey {
+      resource: resource.to_owned(),
+      verb: verb.to_owned(),
+    }
+  }
+}
+
+/// `(group, resource)` pairs covering the built-in kinds this module lists.
+const PERMISSION_RESOURCE_KINDS: &[(&str, &str)] = &[
+  ("", "pods"),
+  ("", "services"),
+  ("", "configmaps"),
+  ("", "secrets"),
+  ("", "replicationcontrollers"),
+  ("", "persistentvolumeclaims"),
+  ("", "persistentvolumes"),
+  ("", "serviceaccounts"),
+  ("", "nodes"),
+  ("", "namespaces"),
+  ("apps", "deployments"),
+  ("apps", "statefulsets"),
+  ("apps", "daemonsets"),
+  ("apps", "replicasets"),
+  ("batch", "jobs"),
+  ("batch", "cronjobs"),
+  ("networking.k8s.io", "ingresses"),
+  ("storage.k8s.io", "storageclasses"),
+  ("rbac.authorization.k8s.io", "roles"),
+  ("rbac.authorization.k8s.io", "rolebindings"),
+  ("rbac.authorization.k8s.io", "clusterroles"),
+  ("rbac.authorization.k8s.io", "clusterrolebindings"),
+];
+
+const PERMISSION_VERBS: &[&str] = &["list", "get", "delete", "patch", "create"];
+
+fn namespace_cache_key(namespace: &Option<String>) -> String {
+  namespace.clone().unwrap_or_default()
+}
+
+/// Stores idle for longer than this are dropped on the next cache sweep.
+const RESOURCE_STORE_IDLE_TIMEOUT: Duration = Duration::from_secs(5 * 60);
+
+/// A reflector store plus its live watch stream, kept alive across polls
+/// until it sits idle past `RESOURCE_STORE_IDLE_TIMEOUT`.
+struct WatchedResource<K>
+where
+  K: kube::Resource,
+  K::DynamicType: Default,
+{
+  store: reflector::Store<K>,
+  stream: Pin<Box<dyn Stream<Item = Result<K, watcher::Error>> + Send>>,
+  last_accessed: Instant,
+}
+
+impl<K> WatchedResource<K>
+where
+  K: Clone + DeserializeOwned + fmt::Debug + Send + Sync + 'static,
+  K::DynamicType: Default + Eq + std::hash::Hash + Clone + Send + Sync,
+{
+  fn new(api: Api<K>) -> Self {
+    let (store, writer) = reflector::store();
+    let stream = watcher(api, watcher::Config::default())
+      .default_backoff()
+      .reflect(writer)
+      .applied_objects()
+      .boxed();
+
+    WatchedResource {
+      store,
+      stream,
+      last_accessed: Instant::now(),
+    }
+  }
+}
+
+/// Type-erased access to a `WatchedResource<K>`'s bookkeeping, so the cache
+/// can sweep idle entries without knowing their concrete `K`.
+trait ResourceStoreEntry: Any + Send {
+  fn last_accessed(&self) -> Instant;
+  fn as_any(&self) -> &dyn Any;
+  fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<K> ResourceStoreEntry for WatchedResource<K>
+where
+  K: kube::Resource + Send + 'static,
+  K::DynamicType: Default + Send,
+{
+  fn last_accessed(&self) -> Instant {
+    self.last_accessed
+  }
+
+  fn as_any(&self) -> &dyn Any {
+    self
+  }
+
+  fn as_any_mut(&mut self) -> &mut dyn Any {
+    self
+  }
+}
+
+/// Per-kind reflector stores, keyed by the watched type, the active
+/// context (so switching clusters never serves another cluster's stream)
+/// and a scope key. Entries idle longer than `RESOURCE_STORE_IDLE_TIMEOUT`
+/// are swept out on access so long sessions don't accumulate open watches
+/// forever.
+type ResourceStoreKey = (TypeId, String);
+
+fn resource_stores() -> &'static Mutex<HashMap<ResourceStoreKey, Box<dyn ResourceStoreEntry>>> {
+  static STORES: OnceLock<Mutex<HashMap<ResourceStoreKey, Box<dyn ResourceStoreEntry>>>> =
+    OnceLock::new();
+  STORES.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+fn evict_idle_stores(
+  stores: &mut HashMap<ResourceStoreKey, Box<dyn ResourceStoreEntry>>,
+  keep: &ResourceStoreKey,
+) {
+  stores.retain(|key, entry| {
+    key == keep || entry.last_accessed().elapsed() < RESOURCE_STORE_IDLE_TIMEOUT
+  });
+}
+
+/// A group/version/kind discovered via the cluster's discovery API.
+#[derive(Clone)]
+pub struct DiscoveredResource {
+  pub group: String,
+  pub version: String,
+  pub kind: String,
+  pub plural: String,
+  pub namespaced: bool,
+}
+
+/// A row projected from an arbitrary `DynamicObject`, for the generic
+/// "Custom Resources" view.
+#[derive(Clone)]
+pub struct KubeCrd {
+  pub namespace: String,
+  pub name: String,
+  pub age: String,
+  pub labels: String,
+}
+
+/// Formats a duration the way `kubectl get` shows AGE.
+fn format_age(duration: chrono::Duration) -> String {
+  let minutes = duration.num_minutes().max(0);
+  if minutes < 60 {
+    format!("{}m", minutes)
+  } else if minutes < 60 * 24 {
+    format!("{}h", minutes / 60)
+  } else {
+    format!("{}d", minutes / (60 * 24))
+  }
+}
+
+impl From<DynamicObject> for KubeCrd {
+  fn from(obj: DynamicObject) -> Self {
+    KubeCrd {
+      namespace: obj.metadata.namespace.clone().unwrap_or_default(),
+      name: obj.metadata.name.clone().unwrap_or_default(),
+      age: obj
+        .metadata
+        .creation_timestamp
+        .as_ref()
+        .map(|t| format_age(Utc::now() - t.0))
+        .unwrap_or_default(),
+      labels: obj
+        .metadata
+        .labels
+        .clone()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(k, v)| format!("{}={}", k, v))
+        .collect::<Vec<_>>()
+        .join(","),
+    }
+  }
+}
+
+/// Snapshot of a rollout in progress, reported to the UI as replicas update.
+#[derive(Clone, Copy, Default)]
+pub struct RolloutProgress {
+  pub desired: i32,
+  pub updated: i32,
+  pub ready: i32,
+}
+
 impl<'a> Network<'a> {
   pub async fn get_kube_config(&self) {
     match Kubeconfig::read() {
@@ -187,6 +402,184 @@ impl<'a> Network<'a> {
     }
   }
 
+  pub async fn cordon_node(&self, node_name: &str) {
+    self.set_node_unschedulable(node_name, true).await;
+  }
+
+  pub async fn uncordon_node(&self, node_name: &str) {
+    self.set_node_unschedulable(node_name, false).await;
+  }
+
+  async fn set_node_unschedulable(&self, node_name: &str, unschedulable: bool) {
+    let api: Api<Node> = Api::all(self.client.clone());
+    let patch = json!({ "spec": { "unschedulable": unschedulable } });
+
+    match api
+      .patch(node_name, &PatchParams::default(), &Patch::Merge(&patch))
+      .await
+    {
+      Ok(_) => self.get_nodes().await,
+      Err(e) => {
+        self
+          .handle_error(anyhow!(
+            "Failed to {} node {}. {:?}",
+            if unschedulable { "cordon" } else { "uncordon" },
+            node_name,
+            e
+          ))
+          .await;
+      }
+    }
+  }
+
+  /// Evicts every evictable pod on `node_name` and waits for each to
+  /// actually terminate, retrying PDB-blocked `429`s with backoff.
+  /// `should_cancel` is polled between pods and while waiting, to allow
+  /// aborting.
+  pub async fn drain_node(
+    &self,
+    node_name: &str,
+    grace_period_seconds: i64,
+    should_cancel: impl Fn() -> bool,
+  ) {
+    let api_pods: Api<Pod> = Api::all(self.client.clone());
+    let lp = ListParams::default().fields(&format!("spec.nodeName={}", node_name));
+
+    let pods = match api_pods.list(&lp).await {
+      Ok(pods) => pods,
+      Err(e) => {
+        self
+          .handle_error(anyhow!("Failed to list pods on node {}. {:?}", node_name, e))
+          .await;
+        return;
+      }
+    };
+
+    for pod in pods {
+      if should_cancel() {
+        break;
+      }
+
+      let name = match &pod.metadata.name {
+        Some(name) => name.clone(),
+        None => continue,
+      };
+      let namespace = pod.metadata.namespace.clone().unwrap_or_default();
+
+      if Self::is_daemonset_or_static_pod(&pod) {
+        continue;
+      }
+
+      self
+        .evict_pod_with_backoff(
+          &namespace,
+          &name,
+          pod.metadata.uid.as_deref(),
+          grace_period_seconds,
+          &should_cancel,
+        )
+        .await;
+    }
+  }
+
+  /// True for DaemonSet-owned pods and static/mirror pods, which draining should skip.
+  fn is_daemonset_or_static_pod(pod: &Pod) -> bool {
+    let is_daemonset = pod
+      .metadata
+      .owner_references
+      .iter()
+      .flatten()
+      .any(|owner| owner.kind == "DaemonSet");
+
+    let is_mirror_pod = pod
+      .metadata
+      .annotations
+      .iter()
+      .flatten()
+      .any(|(key, _)| key == "kubernetes.io/config.mirror");
+
+    is_daemonset || is_mirror_pod
+  }
+
+  async fn evict_pod_with_backoff(
+    &self,
+    namespace: &str,
+    pod_name: &str,
+    pod_uid: Option<&str>,
+    grace_period_seconds: i64,
+    should_cancel: &impl Fn() -> bool,
+  ) {
+    let api: Api<Pod> = Api::namespaced(self.client.clone(), namespace);
+    let evict_params = EvictParams {
+      delete_options: Some(DeleteOptions {
+        grace_period_seconds: Some(grace_period_seconds),
+        ..Default::default()
+      }),
+      ..Default::default()
+    };
+
+    let mut backoff = Duration::from_secs(1);
+    loop {
+      if should_cancel() {
+        return;
+      }
+
+      match api.evict(pod_name, &evict_params).await {
+        Ok(_) => {
+          self
+            .await_pod_deletion(&api, pod_name, pod_uid, should_cancel)
+            .await;
+          return;
+        }
+        Err(kube::Error::Api(ae)) if ae.code == 429 => {
+          // Blocked by a PodDisruptionBudget; back off and let other
+          // replicas come back up before retrying this eviction.
+          sleep(backoff).await;
+          backoff = (backoff * 2).min(Duration::from_secs(30));
+        }
+        Err(kube::Error::Api(ae)) if ae.code == 404 => return,
+        Err(e) => {
+          self
+            .handle_error(anyhow!(
+              "Failed to evict pod {}/{}. {:?}",
+              namespace,
+              pod_name,
+              e
+            ))
+            .await;
+          return;
+        }
+      }
+    }
+  }
+
+  /// Polls until an evicted pod is actually gone (matched by UID, so a
+  /// replacement reusing its name does not count), so `drain_node` waits for
+  /// evicted pods to finish terminating rather than returning as soon as the
+  /// eviction requests were merely accepted. A failed lookup also ends the wait.
+  async fn await_pod_deletion(
+    &self,
+    api: &Api<Pod>,
+    pod_name: &str,
+    pod_uid: Option<&str>,
+    should_cancel: &impl Fn() -> bool,
+  ) {
+    loop {
+      if should_cancel() {
+        return;
+      }
+
+      match api.get_opt(pod_name).await {
+        Ok(None) => return,
+        Ok(Some(pod)) if pod.metadata.uid.as_deref() == pod_uid => {
+          sleep(Duration::from_secs(2)).await
+        }
+        Ok(Some(_)) => return,
+        Err(_) => return,
+      }
+    }
+  }
+
   pub async fn get_namespaces(&self) {
     let api: Api<Namespace> = Api::all(self.client.clone());
 
@@ -296,6 +689,190 @@ impl<'a> Network<'a> {
     app.data.daemon_sets.set_items(items);
   }
 
+  /// Watches a Deployment until `observedGeneration` and replica counts show
+  /// the rollout is complete, or `timeout` elapses.
+  pub async fn await_deployment_rollout(&self, name: &str, timeout: Duration) {
+    let api: Api<Deployment> = self.get_namespaced_api().await;
+
+    self
+      .await_rollout(api, "deployment", name, timeout, |deployment| {
+        let spec = deployment.spec.clone().unwrap_or_default();
+        let status = deployment.status.clone().unwrap_or_default();
+        let desired = spec.replicas.unwrap_or(1);
+
+        let generation_observed = status.observed_generation.unwrap_or_default()
+          >= deployment.metadata.generation.unwrap_or_default();
+
+        let ready = generation_observed
+          && status.updated_replicas.unwrap_or_default() == desired
+          && status.replicas.unwrap_or_default() == desired
+          && status.available_replicas.unwrap_or_default() == desired;
+
+        (
+          RolloutProgress {
+            desired,
+            updated: status.updated_replicas.unwrap_or_default(),
+            ready: status.available_replicas.unwrap_or_default(),
+          },
+          ready,
+        )
+      })
+      .await;
+  }
+
+  /// As `await_deployment_rollout`, but for a StatefulSet.
+  pub async fn await_stateful_set_rollout(&self, name: &str, timeout: Duration) {
+    let api: Api<StatefulSet> = self.get_namespaced_api().await;
+
+    self
+      .await_rollout(api, "statefulset", name, timeout, |sts| {
+        let spec = sts.spec.clone().unwrap_or_default();
+        let status = sts.status.clone().unwrap_or_default();
+        let desired = spec.replicas.unwrap_or(1);
+
+        let generation_observed =
+          status.observed_generation.unwrap_or_default() >= sts.metadata.generation.unwrap_or_default();
+
+        let ready = generation_observed
+          && status.updated_replicas.unwrap_or_default() == desired
+          && status.replicas == desired
+          && status.ready_replicas.unwrap_or_default() == desired;
+
+        (
+          RolloutProgress {
+            desired,
+            updated: status.updated_replicas.unwrap_or_default(),
+            ready: status.ready_replicas.unwrap_or_default(),
+          },
+          ready,
+        )
+      })
+      .await;
+  }
+
+  /// As `await_deployment_rollout`, but for a DaemonSet.
+  pub async fn await_daemon_set_rollout(&self, name: &str, timeout: Duration) {
+    let api: Api<DaemonSet> = self.get_namespaced_api().await;
+
+    self
+      .await_rollout(api, "daemonset", name, timeout, |ds| {
+        let status = ds.status.clone().unwrap_or_default();
+        let desired = status.desired_number_scheduled;
+
+        let generation_observed = status.observed_generation.unwrap_or_default()
+          >= ds.metadata.generation.unwrap_or_default();
+
+        let ready = generation_observed
+          && status.updated_number_scheduled.unwrap_or_default() == desired
+          && status.number_ready == desired;
+
+        (
+          RolloutProgress {
+            desired,
+            updated: status.updated_number_scheduled.unwrap_or_default(),
+            ready: status.number_ready,
+          },
+          ready,
+        )
+      })
+      .await;
+  }
+
+  /// As `await_deployment_rollout`, but for a single Pod's `Ready` condition.
+  pub async fn await_pod_ready(&self, name: &str, timeout: Duration) {
+    let api: Api<Pod> = self.get_namespaced_api().await;
+
+    self
+      .await_rollout(api, "pod", name, timeout, |pod| {
+        let is_ready = pod
+          .status
+          .as_ref()
+          .and_then(|status| status.conditions.as_ref())
+          .into_iter()
+          .flatten()
+          .any(|condition| condition.type_ == "Ready" && condition.status == "True");
+
+        (
+          RolloutProgress {
+            desired: 1,
+            updated: 1,
+            ready: is_ready as i32,
+          },
+          is_ready,
+        )
+      })
+      .await;
+  }
+
+  /// Shared watch loop behind the `await_*_rollout`/`await_pod_ready`
+  /// helpers above: watches `name`, reports progress as events arrive via
+  /// `progress_and_readiness`, and returns once it reports `true` or
+  /// `timeout` elapses.
+  async fn await_rollout<K, R>(
+    &self,
+    api: Api<K>,
+    kind: &str,
+    name: &str,
+    timeout: Duration,
+    mut progress_and_readiness: R,
+  ) where
+    K: kube::Resource<Scope = NamespaceResourceScope>,
+    K::DynamicType: Default,
+    K: Clone + DeserializeOwned + fmt::Debug + Send + Sync + 'static,
+    R: FnMut(&K) -> (RolloutProgress, bool),
+  {
+    let result = tokio::time::timeout(timeout, async {
+      let stream =
+        watcher(api, watcher::Config::default().fields(&format!("metadata.name={}", name)))
+          .applied_objects();
+      tokio::pin!(stream);
+
+      while let Some(obj) = stream.try_next().await.map_err(|e| anyhow!("{:?}", e))? {
+        let (progress, ready) = progress_and_readiness(&obj);
+        self.report_rollout_progress(progress).await;
+
+        if ready {
+          return Ok::<(), anyhow::Error>(());
+        }
+      }
+
+      Err(anyhow!("Watch stream for {} {} ended unexpectedly", kind, name))
+    })
+    .await;
+
+    self.finish_rollout_wait(kind, name, result).await;
+  }
+
+  async fn report_rollout_progress(&self, progress: RolloutProgress) {
+    let mut app = self.app.lock().await;
+    app.data.rollout_status = Some(progress);
+  }
+
+  async fn finish_rollout_wait(
+    &self,
+    kind: &str,
+    name: &str,
+    result: Result<anyhow::Result<()>, tokio::time::error::Elapsed>,
+  ) {
+    match result {
+      Ok(Ok(())) => {}
+      Ok(Err(e)) => {
+        self
+          .handle_error(anyhow!("Rollout for {} {} failed. {:?}", kind, name, e))
+          .await;
+      }
+      Err(_) => {
+        self
+          .handle_error(anyhow!(
+            "Timed out waiting for {} {} to roll out (progress deadline exceeded).",
+            kind,
+            name
+          ))
+          .await;
+      }
+    }
+  }
+
   pub async fn get_storage_classes(&self) {
     let items: Vec<KubeStorageClass> = self.get_resources(StorageClass::into).await;
 
@@ -361,23 +938,60 @@ impl<'a> Network<'a> {
     app.data.service_accounts.set_items(items);
   }
 
-  /// calls the kubernetes API to list the given resource for either selected namespace or all namespaces
-  async fn get_namespaced_resources<K: ApiResource, T, F>(&self, map_fn: F) -> Vec<T>
-  where
-    <K as ApiResource>::DynamicType: Default,
-    K: kube::Resource<Scope = NamespaceResourceScope>,
-    K: Clone + DeserializeOwned + fmt::Debug,
-    F: Fn(K) -> T,
-  {
-    let api: Api<K> = self.get_namespaced_api().await;
-    let lp = ListParams::default();
-    match api.list(&lp).await {
-      Ok(list) => list.into_iter().map(map_fn).collect::<Vec<_>>(),
+  /// Enumerates every API group and resource the cluster serves, built-ins and CRDs alike.
+  pub async fn discover_api_resources(&self) -> Vec<DiscoveredResource> {
+    match Discovery::new(self.client.clone()).run().await {
+      Ok(discovery) => discovery
+        .groups()
+        .flat_map(|group| {
+          group
+            .recommended_resources()
+            .into_iter()
+            .map(|(api_resource, caps)| DiscoveredResource {
+              group: api_resource.group.clone(),
+              version: api_resource.version.clone(),
+              kind: api_resource.kind.clone(),
+              plural: api_resource.plural.clone(),
+              namespaced: matches!(caps.scope, Scope::Namespaced),
+            })
+        })
+        .collect(),
+      Err(e) => {
+        self
+          .handle_error(anyhow!("Failed to discover API resources. {:?}", e))
+          .await;
+        vec![]
+      }
+    }
+  }
+
+  pub async fn get_custom_resources(&self, resource: &DiscoveredResource) {
+    let items = self.get_dynamic_resources(resource).await;
+
+    let mut app = self.app.lock().await;
+    app.data.custom_resources.set_items(items);
+  }
+
+  /// Lists a discovered kind, CRDs included, as `DynamicObject`s. Uses the
+  /// plural reported by discovery rather than guessing one from the kind, so
+  /// CRDs with an irregular plural (e.g. not a simple `+s`) still resolve.
+  async fn get_dynamic_resources(&self, resource: &DiscoveredResource) -> Vec<KubeCrd> {
+    let gvk = GroupVersionKind::gvk(&resource.group, &resource.version, &resource.kind);
+    let api_resource = DynamicApiResource::from_gvk_with_plural(&gvk, &resource.plural);
+
+    let api: Api<DynamicObject> = if resource.namespaced {
+      self.get_namespaced_dynamic_api(&api_resource).await
+    } else {
+      Api::all_with(self.client.clone(), &api_resource)
+    };
+
+    match api.list(&ListParams::default()).await {
+      Ok(list) => list.items.into_iter().map(KubeCrd::from).collect(),
       Err(e) => {
         self
           .handle_error(anyhow!(
-            "Failed to get namespaced resource {}. {:?}",
-            std::any::type_name::<T>(),
+            "Failed to get custom resource {}. {:?}",
+            resource.kind,
             e
           ))
           .await;
@@ -386,27 +1000,188 @@ impl<'a> Network<'a> {
     }
   }
 
+  async fn get_namespaced_dynamic_api(&self, resource: &DynamicApiResource) -> Api<DynamicObject> {
+    let app = self.app.lock().await;
+    match &app.data.selected.ns {
+      Some(ns) => Api::namespaced_with(self.client.clone(), ns, resource),
+      None => Api::all_with(self.client.clone(), resource),
+    }
+  }
+
+  /// Batch-checks `SelfSubjectAccessReview` for every (resource, verb) pair and
+  /// caches the resulting `status.allowed` for the current context/namespace.
+  pub async fn refresh_permissions(&self) {
+    let (context, namespace) = {
+      let app = self.app.lock().await;
+      (
+        app.data.selected.context.clone().unwrap_or_default(),
+        app.data.selected.ns.clone(),
+      )
+    };
+
+    let api: Api<SelfSubjectAccessReview> = Api::all(self.client.clone());
+
+    let checks = PERMISSION_RESOURCE_KINDS.iter().flat_map(|(group, resource)| {
+      PERMISSION_VERBS
+        .iter()
+        .map(move |verb| (*group, *resource, *verb))
+    });
+
+    let reviews = join_all(checks.map(|(group, resource, verb)| {
+      let api = &api;
+      let namespace = namespace.clone();
+      async move {
+        let review = SelfSubjectAccessReview {
+          spec: SelfSubjectAccessReviewSpec {
+            resource_attributes: Some(ResourceAttributes {
+              namespace,
+              group: Some(group.to_owned()),
+              resource: Some(resource.to_owned()),
+              verb: Some(verb.to_owned()),
+              ..Default::default()
+            }),
+            ..Default::default()
+          },
+          ..Default::default()
+        };
+
+        let outcome = api
+          .create(&PostParams::default(), &review)
+          .await
+          .map_err(|e| anyhow!("{:?}", e))
+          .map(|review| review.status.map(|status| status.allowed).unwrap_or(false));
+
+        (PermissionKey::new(resource, verb), outcome)
+      }
+    }))
+    .await;
+
+    let mut allowed = HashMap::new();
+    let mut failed = 0;
+    for (key, outcome) in reviews {
+      match outcome {
+        Ok(is_allowed) => {
+          allowed.insert(key, is_allowed);
+        }
+        Err(_) => failed += 1,
+      }
+    }
+
+    if failed > 0 {
+      self
+        .handle_error(anyhow!(
+          "Failed to refresh {} of {} permission checks; affected tabs/actions may be stale until the next refresh.",
+          failed,
+          PERMISSION_RESOURCE_KINDS.len() * PERMISSION_VERBS.len()
+        ))
+        .await;
+    }
+
+    let mut app = self.app.lock().await;
+    app
+      .data
+      .permissions
+      .insert((context, namespace_cache_key(&namespace)), allowed);
+  }
+
+  /// returns the given resource for either selected namespace or all namespaces, read from a watch-backed store
+  async fn get_namespaced_resources<K: ApiResource, T, F>(&self, map_fn: F) -> Vec<T>
+  where
+    <K as ApiResource>::DynamicType: Default + Eq + std::hash::Hash + Clone + Send + Sync,
+    K: kube::Resource<Scope = NamespaceResourceScope>,
+    K: Clone + DeserializeOwned + fmt::Debug + Send + Sync + 'static,
+    F: Fn(K) -> T,
+  {
+    let app = self.app.lock().await;
+    let context = app.data.selected.context.clone().unwrap_or_default();
+    let ns = app.data.selected.ns.clone();
+    drop(app);
+
+    let api: Api<K> = self.get_namespaced_api().await;
+    let scope = ns.unwrap_or_else(|| "__all_namespaces__".to_owned());
+    self.watch_resources(api, &context, &scope, map_fn).await
+  }
+
   async fn get_resources<K: ApiResource, T, F>(&self, map_fn: F) -> Vec<T>
   where
-    <K as ApiResource>::DynamicType: Default,
-    K: Clone + DeserializeOwned + fmt::Debug,
+    <K as ApiResource>::DynamicType: Default + Eq + std::hash::Hash + Clone + Send + Sync,
+    K: Clone + DeserializeOwned + fmt::Debug + Send + Sync + 'static,
     F: Fn(K) -> T,
   {
+    let context = self.app.lock().await.data.selected.context.clone().unwrap_or_default();
     let api: Api<K> = Api::all(self.client.clone());
-    let lp = ListParams::default();
-    match api.list(&lp).await {
-      Ok(list) => list.into_iter().map(map_fn).collect::<Vec<_>>(),
-      Err(e) => {
-        self
-          .handle_error(anyhow!(
-            "Failed to get resource {}. {:?}",
-            std::any::type_name::<T>(),
-            e
-          ))
-          .await;
-        vec![]
+    self.watch_resources(api, &context, "__cluster__", map_fn).await
+  }
+
+  /// Reads the long-lived reflector store for `K`/`context`/`scope`, draining
+  /// any new events first without blocking on a live watch connection. The
+  /// context is part of the cache key so switching clusters starts a fresh
+  /// watch instead of continuing to serve the previous cluster's data, and
+  /// idle stores (e.g. from a namespace no longer visited) are swept out on
+  /// access.
+  async fn watch_resources<K, T, F>(
+    &self,
+    api: Api<K>,
+    context: &str,
+    scope: &str,
+    map_fn: F,
+  ) -> Vec<T>
+  where
+    <K as ApiResource>::DynamicType: Default + Eq + std::hash::Hash + Clone + Send + Sync,
+    K: Clone + DeserializeOwned + fmt::Debug + Send + Sync + 'static,
+    F: Fn(K) -> T,
+  {
+    let key = (TypeId::of::<K>(), format!("{}::{}", context, scope));
+
+    let error = {
+      let mut stores = resource_stores().lock().unwrap();
+      evict_idle_stores(&mut stores, &key);
+
+      let watched = stores
+        .entry(key.clone())
+        .or_insert_with(|| Box::new(WatchedResource::<K>::new(api)))
+        .as_any_mut()
+        .downcast_mut::<WatchedResource<K>>()
+        .expect("resource store registered under the wrong type");
+      watched.last_accessed = Instant::now();
+
+      let mut error = None;
+      while let Some(next) = watched.stream.as_mut().next().now_or_never() {
+        match next {
+          Some(Ok(_)) => continue,
+          Some(Err(e)) => {
+            error = Some(e);
+            break;
+          }
+          None => break,
+        }
       }
+      error
+    };
+
+    if let Some(e) = error {
+      self
+        .handle_error(anyhow!(
+          "Failed to watch resource {}. {:?}",
+          std::any::type_name::<T>(),
+          e
+        ))
+        .await;
+      return vec![];
     }
+
+    let stores = resource_stores().lock().unwrap();
+    let watched = stores
+      .get(&key)
+      .and_then(|w| w.as_any().downcast_ref::<WatchedResource<K>>())
+      .expect("resource store was just inserted above");
+
+    watched
+      .store
+      .state()
+      .iter()
+      .map(|obj| map_fn((**obj).clone()))
+      .collect()
   }
 
   async fn get_namespaced_api<K: ApiResource>(&self) -> Api<K>